@@ -1,39 +1,127 @@
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, GreetResp, InstantiateMsg, QueryMsg};
-use crate::state::{ADMINS, DONATION_DENOM};
+use crate::msg::{ExecuteMsg, GreetResp, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::state::{
+    ContractInfo, ADMINS, ALLOWANCES, CONTRACT_INFO, DEADLINE, DONATION_DENOM, GOAL, HOOKS,
+    MIN_BOND, START, TOKENS_PER_WEIGHT, TOTAL, TOTAL_BONDED, TOTAL_RAISED, UNBONDING_PERIOD,
+};
 use cosmwasm_std::{
-    to_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+    to_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult, Uint128,
 };
 
+pub const CONTRACT_NAME: &str = "crates.io:cosmwasm-book-project";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 pub fn instantiate(
     deps: DepsMut,
     _env: Env,
     _info: MessageInfo,
     msg: InstantiateMsg,
-) -> StdResult<Response> {
-    let admins: StdResult<Vec<_>> = msg
-        .admins
-        .into_iter()
-        .map(|addr| deps.api.addr_validate(&addr))
-        .collect();
-    ADMINS.save(deps.storage, &admins?)?;
+) -> Result<Response, ContractError> {
+    if msg.tokens_per_weight.is_zero() {
+        return Err(ContractError::ZeroTokensPerWeight {});
+    }
+    if msg.min_bond.is_zero() {
+        return Err(ContractError::ZeroMinBond {});
+    }
+
+    for admin in msg.admins {
+        let admin = deps.api.addr_validate(&admin)?;
+        ADMINS.save(deps.storage, &admin, &Empty {})?;
+    }
     DONATION_DENOM.save(deps.storage, &msg.donation_denom)?;
 
+    TOKENS_PER_WEIGHT.save(deps.storage, &msg.tokens_per_weight)?;
+    MIN_BOND.save(deps.storage, &msg.min_bond)?;
+    UNBONDING_PERIOD.save(deps.storage, &msg.unbonding_period)?;
+    TOTAL.save(deps.storage, &Uint128::zero())?;
+    TOTAL_BONDED.save(deps.storage, &Uint128::zero())?;
+
+    GOAL.save(deps.storage, &msg.goal)?;
+    START.save(deps.storage, &msg.start)?;
+    DEADLINE.save(deps.storage, &msg.deadline)?;
+    TOTAL_RAISED.save(deps.storage, &Uint128::zero())?;
+
+    CONTRACT_INFO.save(
+        deps.storage,
+        &ContractInfo {
+            contract: CONTRACT_NAME.to_owned(),
+            version: CONTRACT_VERSION.to_owned(),
+        },
+    )?;
+
     Ok(Response::new())
 }
 
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let stored = CONTRACT_INFO.may_load(deps.storage)?;
+
+    match &stored {
+        Some(info) if info.contract != CONTRACT_NAME => {
+            return Err(ContractError::CannotMigrate {
+                reason: format!(
+                    "contract type mismatch: storage holds `{}`, binary is `{}`",
+                    info.contract, CONTRACT_NAME
+                ),
+            });
+        }
+        Some(info) => {
+            let previous = semver::Version::parse(&info.version).map_err(|err| {
+                ContractError::CannotMigrate {
+                    reason: err.to_string(),
+                }
+            })?;
+            let new = semver::Version::parse(CONTRACT_VERSION).map_err(|err| {
+                ContractError::CannotMigrate {
+                    reason: err.to_string(),
+                }
+            })?;
+
+            if previous > new {
+                return Err(ContractError::CannotMigrate {
+                    reason: format!("cannot downgrade from v{previous} to v{new}"),
+                });
+            }
+        }
+        None => {
+            // Pre-donation deployments never set `DONATION_DENOM` — give migrating instances one.
+            if DONATION_DENOM.may_load(deps.storage)?.is_none() {
+                DONATION_DENOM.save(deps.storage, &"ujuno".to_owned())?;
+            }
+        }
+    }
+
+    CONTRACT_INFO.save(
+        deps.storage,
+        &ContractInfo {
+            contract: CONTRACT_NAME.to_owned(),
+            version: CONTRACT_VERSION.to_owned(),
+        },
+    )?;
+
+    Ok(Response::new().add_attribute("action", "migrate"))
+}
+
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     use QueryMsg::*;
 
     match msg {
         Greet {} => to_binary(&query::greet()?),
-        AdminsList {} => to_binary(&query::admins_list(deps)?),
+        AdminsList { start_after, limit } => {
+            to_binary(&query::admins_list(deps, start_after, limit)?)
+        }
+        Hooks {} => to_binary(&query::hooks(deps)?),
+        Funders { start_after, limit } => to_binary(&query::funders(deps, start_after, limit)?),
+        TotalRaised {} => to_binary(&query::total_raised(deps)?),
+        Allowance { spender } => to_binary(&query::allowance(deps, env, spender)?),
+        AllAllowances { start_after, limit } => {
+            to_binary(&query::all_allowances(deps, env, start_after, limit)?)
+        }
     }
 }
 
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
@@ -41,16 +129,46 @@ pub fn execute(
 
     match msg {
         AddMembers { admins } => exec::add_members(deps, info, admins),
-        Leave {} => exec::leave(deps, info).map_err(Into::into),
-        Donate {} => exec::donate(deps, info),
+        Leave {} => exec::leave(deps, info),
+        Donate {} => exec::donate(deps, env, info),
+        Bond {} => exec::bond(deps, info),
+        Unbond { amount } => exec::unbond(deps, env, info, amount),
+        Claim {} => exec::claim(deps, env, info),
+        AddHook { addr } => exec::add_hook(deps, info, addr),
+        RemoveHook { addr } => exec::remove_hook(deps, info, addr),
+        Distribute {} => exec::distribute(deps, env),
+        Refund {} => exec::refund(deps, env, info),
+        IncreaseAllowance {
+            spender,
+            amount,
+            expires,
+        } => exec::increase_allowance(deps, env, info, spender, amount, expires),
+        DecreaseAllowance {
+            spender,
+            amount,
+            expires,
+        } => exec::decrease_allowance(deps, env, info, spender, amount, expires),
+        Spend { to, amount } => exec::spend(deps, env, info, to, amount),
     }
 }
 
 mod query {
-    use crate::msg::AdminListResp;
+    use cosmwasm_std::{coin, Order};
+    use cw_storage_plus::Bound;
+
+    use cw_utils::Expiration;
+
+    use crate::msg::{
+        AdminListResp, AllAllowancesResp, AllowanceInfo, AllowanceResp, FunderInfo, FundersResp,
+        HooksResp, TotalRaisedResp,
+    };
+    use crate::state::{Allowance, FUNDERS};
 
     use super::*;
 
+    const MAX_LIMIT: u32 = 30;
+    const DEFAULT_LIMIT: u32 = 10;
+
     pub fn greet() -> StdResult<GreetResp> {
         let resp = GreetResp {
             message: "Hello World".to_owned(),
@@ -59,25 +177,159 @@ mod query {
         Ok(resp)
     }
 
-    pub fn admins_list(deps: Deps) -> StdResult<AdminListResp> {
-        let admins = ADMINS.load(deps.storage)?;
+    pub fn admins_list(
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<AdminListResp> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after
+            .map(|addr| deps.api.addr_validate(&addr))
+            .transpose()?;
+        let start = start.as_ref().map(Bound::exclusive);
+
+        let admins = ADMINS
+            .keys(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?;
+
         let resp = AdminListResp { admins };
         Ok(resp)
     }
+
+    pub fn hooks(deps: Deps) -> StdResult<HooksResp> {
+        let hooks = HOOKS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+
+        Ok(HooksResp { hooks })
+    }
+
+    pub fn funders(
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<FundersResp> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after
+            .map(|addr| deps.api.addr_validate(&addr))
+            .transpose()?;
+        let start = start.as_ref().map(Bound::exclusive);
+
+        let funders = FUNDERS
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| {
+                let (addr, amount) = item?;
+                Ok(FunderInfo { addr, amount })
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+
+        Ok(FundersResp { funders })
+    }
+
+    pub fn total_raised(deps: Deps) -> StdResult<TotalRaisedResp> {
+        let total = TOTAL_RAISED.load(deps.storage)?;
+        Ok(TotalRaisedResp { total })
+    }
+
+    /// Returns a spender's remaining allowance, or a zero balance if they have none / it expired.
+    pub fn allowance(deps: Deps, env: Env, spender: String) -> StdResult<AllowanceResp> {
+        let spender = deps.api.addr_validate(&spender)?;
+        let denom = DONATION_DENOM.load(deps.storage)?;
+
+        let allowance = ALLOWANCES.may_load(deps.storage, &spender)?;
+        let allowance = match allowance {
+            Some(allowance) if !allowance.expires.is_expired(&env.block) => allowance,
+            _ => Allowance {
+                balance: coin(0, &denom),
+                expires: Expiration::Never {},
+            },
+        };
+
+        Ok(AllowanceResp {
+            balance: allowance.balance,
+            expires: allowance.expires,
+        })
+    }
+
+    pub fn all_allowances(
+        deps: Deps,
+        env: Env,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<AllAllowancesResp> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after
+            .map(|addr| deps.api.addr_validate(&addr))
+            .transpose()?;
+        let start = start.as_ref().map(Bound::exclusive);
+
+        let allowances = ALLOWANCES
+            .range(deps.storage, start, None, Order::Ascending)
+            .filter(|item| {
+                item.as_ref()
+                    .map(|(_, allowance)| !allowance.expires.is_expired(&env.block))
+                    .unwrap_or(true)
+            })
+            .take(limit)
+            .map(|item| {
+                let (spender, allowance) = item?;
+                Ok(AllowanceInfo {
+                    spender,
+                    balance: allowance.balance,
+                    expires: allowance.expires,
+                })
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+
+        Ok(AllAllowancesResp { allowances })
+    }
 }
 
 mod exec {
-    use cosmwasm_std::{coins, BankMsg, Event};
+    use cosmwasm_std::{coin, coins, Addr, BankMsg, Event, Order, Storage, SubMsg, WasmMsg};
+    use cw_utils::Expiration;
+
+    use crate::msg::{MemberChangedHookMsg, MemberDiff};
+    use crate::state::{Allowance, Claim, CLAIMS, FUNDERS, STAKE};
 
     use super::*;
 
+    /// Weight is zero for stakes below `min_bond`, otherwise `staked / tokens_per_weight`.
+    fn weight_for(staked: Uint128, min_bond: Uint128, tokens_per_weight: Uint128) -> Uint128 {
+        if staked < min_bond {
+            Uint128::zero()
+        } else {
+            staked / tokens_per_weight
+        }
+    }
+
+    /// Notifies every registered hook subscriber about the given membership diff.
+    fn hook_messages(deps: Deps, diffs: Vec<MemberDiff>) -> StdResult<Vec<SubMsg>> {
+        if diffs.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let msg = to_binary(&MemberChangedHookMsg { diffs })?;
+        HOOKS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .map(|hook| -> StdResult<_> {
+                Ok(SubMsg::new(WasmMsg::Execute {
+                    contract_addr: hook?.into_string(),
+                    msg: msg.clone(),
+                    funds: vec![],
+                }))
+            })
+            .collect()
+    }
+
     pub fn add_members(
         deps: DepsMut,
         info: MessageInfo,
         admins: Vec<String>,
     ) -> Result<Response, ContractError> {
-        let mut curr_admins = ADMINS.load(deps.storage)?;
-        if !curr_admins.contains(&info.sender) {
+        if !ADMINS.has(deps.storage, &info.sender) {
             return Err(ContractError::Unauthorized {
                 sender: info.sender,
             });
@@ -91,47 +343,510 @@ mod exec {
             .add_attribute("action", "add_members")
             .add_attribute("added_count", admins.len().to_string());
 
-        let admins: StdResult<Vec<_>> = admins
-            .into_iter()
-            .map(|addr| deps.api.addr_validate(&addr))
-            .collect();
+        let mut diffs = vec![];
+        for admin in admins {
+            let admin = deps.api.addr_validate(&admin)?;
+            if !ADMINS.has(deps.storage, &admin) {
+                diffs.push(MemberDiff::new(admin.clone(), None, Some(1)));
+            }
+            ADMINS.save(deps.storage, &admin, &Empty {})?;
+        }
+
+        let hooks = hook_messages(deps.as_ref(), diffs)?;
+
+        Ok(resp.add_submessages(hooks))
+    }
 
-        curr_admins.append(&mut admins?);
-        ADMINS.save(deps.storage, &curr_admins)?;
+    pub fn leave(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+        let mut diffs = vec![];
+        if ADMINS.has(deps.storage, &info.sender) {
+            ADMINS.remove(deps.storage, &info.sender);
+            diffs.push(MemberDiff::new(info.sender, Some(1), None));
+        }
+
+        let hooks = hook_messages(deps.as_ref(), diffs)?;
+
+        Ok(Response::new().add_submessages(hooks))
+    }
+
+    pub fn add_hook(
+        deps: DepsMut,
+        info: MessageInfo,
+        addr: String,
+    ) -> Result<Response, ContractError> {
+        if !ADMINS.has(deps.storage, &info.sender) {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        let addr = deps.api.addr_validate(&addr)?;
+        HOOKS.save(deps.storage, &addr, &Empty {})?;
+
+        let resp = Response::new()
+            .add_attribute("action", "add_hook")
+            .add_attribute("addr", addr);
+
+        Ok(resp)
+    }
+
+    pub fn remove_hook(
+        deps: DepsMut,
+        info: MessageInfo,
+        addr: String,
+    ) -> Result<Response, ContractError> {
+        if !ADMINS.has(deps.storage, &info.sender) {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        let addr = deps.api.addr_validate(&addr)?;
+        HOOKS.remove(deps.storage, &addr);
+
+        let resp = Response::new()
+            .add_attribute("action", "remove_hook")
+            .add_attribute("addr", addr);
 
         Ok(resp)
     }
 
-    pub fn leave(deps: DepsMut, info: MessageInfo) -> StdResult<Response> {
-        ADMINS.update(deps.storage, move |admins| -> StdResult<_> {
-            let admins = admins
+    /// Whether `Distribute` would have anyone to pay out: either some staker holds non-zero
+    /// weight, or there's at least one admin to fall back to.
+    fn has_eligible_recipients(deps: Deps) -> StdResult<bool> {
+        if !TOTAL.load(deps.storage)?.is_zero() {
+            return Ok(true);
+        }
+        Ok(ADMINS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .next()
+            .transpose()?
+            .is_some())
+    }
+
+    /// Splits `amount` among the current members: weighted by stake if any staker holds
+    /// non-zero weight, otherwise equally among admins (the behavior before stake-weighting existed).
+    fn split_among_members(
+        deps: Deps,
+        amount: Uint128,
+        denom: &str,
+    ) -> Result<Vec<BankMsg>, ContractError> {
+        let total_weight = TOTAL.load(deps.storage)?;
+
+        if total_weight.is_zero() {
+            let admins = ADMINS
+                .keys(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()?;
+            if admins.is_empty() {
+                return Err(ContractError::NoEligibleRecipients {});
+            }
+            let amount_per_admin = amount.u128() / (admins.len() as u128);
+
+            Ok(admins
                 .into_iter()
-                .filter(|admin| *admin != info.sender)
-                .collect();
-            Ok(admins)
+                .map(|admin| (admin, amount_per_admin))
+                .filter(|(_, share)| *share != 0)
+                .map(|(admin, share)| BankMsg::Send {
+                    to_address: admin.to_string(),
+                    amount: coins(share, denom),
+                })
+                .collect())
+        } else {
+            let min_bond = MIN_BOND.load(deps.storage)?;
+            let tokens_per_weight = TOKENS_PER_WEIGHT.load(deps.storage)?;
+
+            STAKE
+                .range(deps.storage, None, None, Order::Ascending)
+                .map(|stake| -> StdResult<_> {
+                    let (member, staked) = stake?;
+                    let weight = weight_for(staked, min_bond, tokens_per_weight);
+                    let share = amount.multiply_ratio(weight, total_weight);
+                    Ok((member, share))
+                })
+                .collect::<StdResult<Vec<_>>>()?
+                .into_iter()
+                .filter(|(_, share)| !share.is_zero())
+                .map(|(member, share)| {
+                    Ok(BankMsg::Send {
+                        to_address: member.to_string(),
+                        amount: coins(share.u128(), denom),
+                    })
+                })
+                .collect()
+        }
+    }
+
+    pub fn donate(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let denom = DONATION_DENOM.load(deps.storage)?;
+        let amount = cw_utils::must_pay(&info, &denom)?;
+
+        let start = START.load(deps.storage)?;
+        let deadline = DEADLINE.load(deps.storage)?;
+        let before_start = start.map_or(false, |start| env.block.time < start);
+        if before_start || env.block.time >= deadline {
+            return Err(ContractError::RoundNotOpen {});
+        }
+
+        FUNDERS.update(deps.storage, &info.sender, |funded| -> StdResult<_> {
+            Ok(funded.unwrap_or_default() + amount)
         })?;
+        TOTAL_RAISED.update(deps.storage, |total| -> StdResult<_> { Ok(total + amount) })?;
+
+        let resp = Response::new()
+            .add_attribute("action", "donate")
+            .add_attribute("sender", info.sender)
+            .add_attribute("amount", amount);
 
-        Ok(Response::new())
+        Ok(resp)
     }
 
-    pub fn donate(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    pub fn distribute(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+        let deadline = DEADLINE.load(deps.storage)?;
+        if env.block.time < deadline {
+            return Err(ContractError::RoundNotFinished {});
+        }
+
+        let goal = GOAL.load(deps.storage)?;
+        let total_raised = TOTAL_RAISED.load(deps.storage)?;
+        if total_raised < goal {
+            return Err(ContractError::GoalNotMet {
+                raised: total_raised,
+                goal,
+            });
+        }
+
         let denom = DONATION_DENOM.load(deps.storage)?;
-        let admins = ADMINS.load(deps.storage)?;
+        let messages = split_among_members(deps.as_ref(), total_raised, &denom)?;
+
+        // Settled funders can no longer be refunded; clear the ledger along with the total.
+        let funders = FUNDERS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for funder in funders {
+            FUNDERS.remove(deps.storage, &funder);
+        }
+        TOTAL_RAISED.save(deps.storage, &Uint128::zero())?;
+
+        let resp = Response::new()
+            .add_messages(messages)
+            .add_attribute("action", "distribute")
+            .add_attribute("amount", total_raised);
+
+        Ok(resp)
+    }
+
+    pub fn refund(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let deadline = DEADLINE.load(deps.storage)?;
+        if env.block.time < deadline {
+            return Err(ContractError::RoundNotFinished {});
+        }
 
-        let donation = cw_utils::must_pay(&info, &denom)?.u128();
+        let goal = GOAL.load(deps.storage)?;
+        let total_raised = TOTAL_RAISED.load(deps.storage)?;
+        // A goal that's been met is normally final, but if there's nobody left to distribute
+        // to, `Distribute` can never run — fall back to refunds rather than stranding the funds.
+        if total_raised >= goal && has_eligible_recipients(deps.as_ref())? {
+            return Err(ContractError::GoalMet {
+                raised: total_raised,
+                goal,
+            });
+        }
 
-        let donation_per_admin = donation / (admins.len() as u128);
+        let amount = FUNDERS
+            .may_load(deps.storage, &info.sender)?
+            .unwrap_or_default();
+        if amount.is_zero() {
+            return Err(ContractError::NothingToRefund {});
+        }
 
-        let messages = admins.into_iter().map(|admin| BankMsg::Send {
-            to_address: admin.to_string(),
-            amount: coins(donation_per_admin, &denom)
-        });
+        FUNDERS.remove(deps.storage, &info.sender);
+        TOTAL_RAISED.update(deps.storage, |total| -> StdResult<_> { Ok(total - amount) })?;
 
+        let denom = DONATION_DENOM.load(deps.storage)?;
         let resp = Response::new()
-            .add_messages(messages)
-            .add_attribute("action", "donate")
-            .add_attribute("amount", donation.to_string())
-            .add_attribute("per_admin", donation_per_admin.to_string());
+            .add_message(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: coins(amount.u128(), &denom),
+            })
+            .add_attribute("action", "refund")
+            .add_attribute("sender", info.sender)
+            .add_attribute("amount", amount);
+
+        Ok(resp)
+    }
+
+    pub fn bond(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+        let denom = DONATION_DENOM.load(deps.storage)?;
+        let amount = cw_utils::must_pay(&info, &denom)?;
+
+        let min_bond = MIN_BOND.load(deps.storage)?;
+        let tokens_per_weight = TOKENS_PER_WEIGHT.load(deps.storage)?;
+
+        let old_stake = STAKE
+            .may_load(deps.storage, &info.sender)?
+            .unwrap_or_default();
+        let new_stake = old_stake + amount;
+        STAKE.save(deps.storage, &info.sender, &new_stake)?;
+
+        let old_weight = weight_for(old_stake, min_bond, tokens_per_weight);
+        let new_weight = weight_for(new_stake, min_bond, tokens_per_weight);
+        TOTAL.update(deps.storage, |total| -> StdResult<_> {
+            Ok(total + new_weight - old_weight)
+        })?;
+        TOTAL_BONDED.update(deps.storage, |total| -> StdResult<_> { Ok(total + amount) })?;
+
+        let resp = Response::new()
+            .add_attribute("action", "bond")
+            .add_attribute("sender", info.sender)
+            .add_attribute("amount", amount);
+
+        Ok(resp)
+    }
+
+    pub fn unbond(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        amount: Uint128,
+    ) -> Result<Response, ContractError> {
+        let old_stake = STAKE
+            .may_load(deps.storage, &info.sender)?
+            .unwrap_or_default();
+        if amount > old_stake {
+            return Err(ContractError::InsufficientStake {
+                requested: amount,
+                available: old_stake,
+            });
+        }
+
+        let min_bond = MIN_BOND.load(deps.storage)?;
+        let tokens_per_weight = TOKENS_PER_WEIGHT.load(deps.storage)?;
+
+        let new_stake = old_stake - amount;
+        if new_stake.is_zero() {
+            STAKE.remove(deps.storage, &info.sender);
+        } else {
+            STAKE.save(deps.storage, &info.sender, &new_stake)?;
+        }
+
+        let old_weight = weight_for(old_stake, min_bond, tokens_per_weight);
+        let new_weight = weight_for(new_stake, min_bond, tokens_per_weight);
+        TOTAL.update(deps.storage, |total| -> StdResult<_> {
+            Ok(total + new_weight - old_weight)
+        })?;
+
+        let unbonding_period = UNBONDING_PERIOD.load(deps.storage)?;
+        let claim = Claim {
+            amount,
+            release_at: unbonding_period.after(&env.block),
+        };
+        CLAIMS.update(deps.storage, &info.sender, move |claims| -> StdResult<_> {
+            let mut claims = claims.unwrap_or_default();
+            claims.push(claim);
+            Ok(claims)
+        })?;
+
+        let resp = Response::new()
+            .add_attribute("action", "unbond")
+            .add_attribute("sender", info.sender)
+            .add_attribute("amount", amount);
+
+        Ok(resp)
+    }
+
+    pub fn claim(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let claims = CLAIMS
+            .may_load(deps.storage, &info.sender)?
+            .unwrap_or_default();
+
+        let (released, pending): (Vec<_>, Vec<_>) = claims
+            .into_iter()
+            .partition(|claim| claim.release_at.is_expired(&env.block));
+
+        if released.is_empty() {
+            return Err(ContractError::NothingToClaim {});
+        }
+
+        if pending.is_empty() {
+            CLAIMS.remove(deps.storage, &info.sender);
+        } else {
+            CLAIMS.save(deps.storage, &info.sender, &pending)?;
+        }
+
+        let amount = released
+            .iter()
+            .fold(Uint128::zero(), |acc, c| acc + c.amount);
+        let denom = DONATION_DENOM.load(deps.storage)?;
+
+        TOTAL_BONDED.update(deps.storage, |total| -> StdResult<_> { Ok(total - amount) })?;
+
+        let resp = Response::new()
+            .add_message(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: coins(amount.u128(), &denom),
+            })
+            .add_attribute("action", "claim")
+            .add_attribute("sender", info.sender)
+            .add_attribute("amount", amount);
+
+        Ok(resp)
+    }
+
+    /// Loads `spender`'s allowance, treating a missing or expired one as an empty balance and
+    /// pruning the expired entry from storage so it doesn't linger forever. The removal only
+    /// persists if the calling message succeeds; a rejected `Spend` leaves the entry in place.
+    fn load_allowance(
+        storage: &mut dyn Storage,
+        env: &Env,
+        spender: &Addr,
+        denom: &str,
+    ) -> StdResult<Allowance> {
+        let allowance = ALLOWANCES.may_load(storage, spender)?;
+        match allowance {
+            Some(allowance) if !allowance.expires.is_expired(&env.block) => Ok(allowance),
+            Some(_) => {
+                ALLOWANCES.remove(storage, spender);
+                Ok(Allowance {
+                    balance: coin(0, denom),
+                    expires: Expiration::Never {},
+                })
+            }
+            None => Ok(Allowance {
+                balance: coin(0, denom),
+                expires: Expiration::Never {},
+            }),
+        }
+    }
+
+    pub fn increase_allowance(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        spender: String,
+        amount: Uint128,
+        expires: Option<Expiration>,
+    ) -> Result<Response, ContractError> {
+        if !ADMINS.has(deps.storage, &info.sender) {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        let spender = deps.api.addr_validate(&spender)?;
+        let denom = DONATION_DENOM.load(deps.storage)?;
+
+        let mut allowance = load_allowance(deps.storage, &env, &spender, &denom)?;
+        allowance.balance.amount += amount;
+        if let Some(expires) = expires {
+            allowance.expires = expires;
+        }
+        ALLOWANCES.save(deps.storage, &spender, &allowance)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "increase_allowance")
+            .add_attribute("spender", spender)
+            .add_attribute("amount", amount);
+
+        Ok(resp)
+    }
+
+    pub fn decrease_allowance(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        spender: String,
+        amount: Uint128,
+        expires: Option<Expiration>,
+    ) -> Result<Response, ContractError> {
+        if !ADMINS.has(deps.storage, &info.sender) {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        let spender = deps.api.addr_validate(&spender)?;
+        let denom = DONATION_DENOM.load(deps.storage)?;
+
+        let mut allowance = load_allowance(deps.storage, &env, &spender, &denom)?;
+        allowance.balance.amount = allowance.balance.amount.saturating_sub(amount);
+        if let Some(expires) = expires {
+            allowance.expires = expires;
+        }
+
+        if allowance.balance.amount.is_zero() {
+            ALLOWANCES.remove(deps.storage, &spender);
+        } else {
+            ALLOWANCES.save(deps.storage, &spender, &allowance)?;
+        }
+
+        let resp = Response::new()
+            .add_attribute("action", "decrease_allowance")
+            .add_attribute("spender", spender)
+            .add_attribute("amount", amount);
+
+        Ok(resp)
+    }
+
+    pub fn spend(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        to: String,
+        amount: Uint128,
+    ) -> Result<Response, ContractError> {
+        let denom = DONATION_DENOM.load(deps.storage)?;
+        let allowance = load_allowance(deps.storage, &env, &info.sender, &denom)?;
+
+        if allowance.balance.amount.is_zero() {
+            return Err(ContractError::NoAllowance {
+                spender: info.sender,
+            });
+        }
+        if amount > allowance.balance.amount {
+            return Err(ContractError::InsufficientAllowance {
+                requested: amount,
+                available: allowance.balance.amount,
+            });
+        }
+
+        let reserved = TOTAL_BONDED.load(deps.storage)? + TOTAL_RAISED.load(deps.storage)?;
+        let balance = deps
+            .as_ref()
+            .querier
+            .query_balance(&env.contract.address, &denom)?
+            .amount;
+        let spendable = balance.saturating_sub(reserved);
+        if amount > spendable {
+            return Err(ContractError::InsufficientTreasuryBalance {
+                requested: amount,
+                available: spendable,
+            });
+        }
+
+        let remaining = allowance.balance.amount - amount;
+        if remaining.is_zero() {
+            ALLOWANCES.remove(deps.storage, &info.sender);
+        } else {
+            ALLOWANCES.save(
+                deps.storage,
+                &info.sender,
+                &Allowance {
+                    balance: coin(remaining.u128(), &denom),
+                    expires: allowance.expires,
+                },
+            )?;
+        }
+
+        let to = deps.api.addr_validate(&to)?;
+        let resp = Response::new()
+            .add_message(BankMsg::Send {
+                to_address: to.to_string(),
+                amount: coins(amount.u128(), &denom),
+            })
+            .add_attribute("action", "spend")
+            .add_attribute("spender", info.sender)
+            .add_attribute("to", to)
+            .add_attribute("amount", amount);
 
         Ok(resp)
     }
@@ -142,11 +857,14 @@ mod test {
     use std::vec;
 
     use cosmwasm_std::{
-        coins, from_binary, testing::{mock_dependencies, mock_env, mock_info}, Addr
+        coin, coins, from_binary,
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr, Uint128,
     };
     use cw_multi_test::{App, ContractWrapper, Executor};
+    use cw_utils::Duration;
 
-    use crate::msg::AdminListResp;
+    use crate::msg::{AdminListResp, AllowanceResp, HooksResp};
 
     use super::*;
 
@@ -174,6 +892,12 @@ mod test {
             InstantiateMsg {
                 admins: vec![],
                 donation_denom: "eth".to_owned(),
+                tokens_per_weight: Uint128::new(10),
+                min_bond: Uint128::new(10),
+                unbonding_period: Duration::Time(1),
+                goal: Uint128::zero(),
+                start: None,
+                deadline: mock_env().block.time.plus_seconds(1000),
             },
         )
         .unwrap();
@@ -203,6 +927,12 @@ mod test {
                 &InstantiateMsg {
                     admins: vec![],
                     donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
                 },
                 &[],
                 "Contract",
@@ -237,6 +967,12 @@ mod test {
                 &InstantiateMsg {
                     admins: vec![],
                     donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
                 },
                 &[],
                 "Contract 1",
@@ -246,7 +982,13 @@ mod test {
 
         let resp: AdminListResp = app
             .wrap()
-            .query_wasm_smart(addr, &QueryMsg::AdminsList {})
+            .query_wasm_smart(
+                addr,
+                &QueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
             .unwrap();
 
         assert_eq!(resp, AdminListResp { admins: vec![] });
@@ -258,6 +1000,12 @@ mod test {
                 &InstantiateMsg {
                     admins: vec!["admin1".to_owned(), "admin2".to_owned()],
                     donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
                 },
                 &[],
                 "Contract 2",
@@ -267,7 +1015,13 @@ mod test {
 
         let resp: AdminListResp = app
             .wrap()
-            .query_wasm_smart(addr, &QueryMsg::AdminsList {})
+            .query_wasm_smart(
+                addr,
+                &QueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
             .unwrap();
 
         assert_eq!(
@@ -279,7 +1033,7 @@ mod test {
     }
 
     #[test]
-    fn unauthorized() {
+    fn admins_list_query_pagination() {
         let mut app = App::default();
 
         let code = ContractWrapper::new(execute, instantiate, query);
@@ -288,10 +1042,20 @@ mod test {
         let addr = app
             .instantiate_contract(
                 code_id,
-                Addr::unchecked("sender"),
+                Addr::unchecked("owner"),
                 &InstantiateMsg {
-                    admins: vec![],
+                    admins: vec![
+                        "admin1".to_owned(),
+                        "admin2".to_owned(),
+                        "admin3".to_owned(),
+                    ],
                     donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
                 },
                 &[],
                 "Contract",
@@ -299,12 +1063,76 @@ mod test {
             )
             .unwrap();
 
-        let err = app
-            .execute_contract(
-                Addr::unchecked("user"),
-                addr,
-                &ExecuteMsg::AddMembers {
-                    admins: vec!["user".to_owned()],
+        let resp: AdminListResp = app
+            .wrap()
+            .query_wasm_smart(
+                addr.clone(),
+                &QueryMsg::AdminsList {
+                    start_after: None,
+                    limit: Some(2),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            resp,
+            AdminListResp {
+                admins: vec![Addr::unchecked("admin1"), Addr::unchecked("admin2")]
+            }
+        );
+
+        let resp: AdminListResp = app
+            .wrap()
+            .query_wasm_smart(
+                addr,
+                &QueryMsg::AdminsList {
+                    start_after: Some("admin2".to_owned()),
+                    limit: Some(2),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            resp,
+            AdminListResp {
+                admins: vec![Addr::unchecked("admin3")]
+            }
+        );
+    }
+
+    #[test]
+    fn unauthorized() {
+        let mut app = App::default();
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked("sender"),
+                &InstantiateMsg {
+                    admins: vec![],
+                    donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("user"),
+                addr,
+                &ExecuteMsg::AddMembers {
+                    admins: vec!["user".to_owned()],
                 },
                 &[],
             )
@@ -318,6 +1146,54 @@ mod test {
         );
     }
 
+    #[test]
+    fn rejects_zero_tokens_per_weight() {
+        let mut deps = mock_dependencies();
+
+        let err = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner", &[]),
+            InstantiateMsg {
+                admins: vec![],
+                donation_denom: "eth".to_owned(),
+                tokens_per_weight: Uint128::zero(),
+                min_bond: Uint128::new(10),
+                unbonding_period: Duration::Time(1),
+                goal: Uint128::zero(),
+                start: None,
+                deadline: mock_env().block.time.plus_seconds(1000),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(ContractError::ZeroTokensPerWeight {}, err);
+    }
+
+    #[test]
+    fn rejects_zero_min_bond() {
+        let mut deps = mock_dependencies();
+
+        let err = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner", &[]),
+            InstantiateMsg {
+                admins: vec![],
+                donation_denom: "eth".to_owned(),
+                tokens_per_weight: Uint128::new(10),
+                min_bond: Uint128::zero(),
+                unbonding_period: Duration::Time(1),
+                goal: Uint128::zero(),
+                start: None,
+                deadline: mock_env().block.time.plus_seconds(1000),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(ContractError::ZeroMinBond {}, err);
+    }
+
     #[test]
     fn add_members() {
         let mut app = App::default();
@@ -332,6 +1208,12 @@ mod test {
                 &InstantiateMsg {
                     admins: vec!["owner".to_owned()],
                     donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
                 },
                 &[],
                 "Contract",
@@ -401,23 +1283,39 @@ mod test {
 
         let addr = app
             .instantiate_contract(
-                code_id, 
+                code_id,
                 Addr::unchecked("owner"),
-                &InstantiateMsg { 
+                &InstantiateMsg {
                     admins: vec!["admins1".to_owned(), "admins2".to_owned()],
                     donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
                 },
                 &[],
                 "Contract",
-                None
+                None,
             )
             .unwrap();
 
         app.execute_contract(
-            Addr::unchecked("user"), 
+            Addr::unchecked("user"),
             addr.clone(),
             &ExecuteMsg::Donate {},
-            &coins(5, "eth")
+            &coins(5, "eth"),
+        )
+        .unwrap();
+
+        app.update_block(|block| block.time = block.time.plus_seconds(1001));
+
+        app.execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &ExecuteMsg::Distribute {},
+            &[],
         )
         .unwrap();
 
@@ -472,31 +1370,51 @@ mod test {
 
         let addr = app
             .instantiate_contract(
-                code_id, 
-                Addr::unchecked("owner"), 
+                code_id,
+                Addr::unchecked("owner"),
                 &InstantiateMsg {
                     admins: vec!["owner1".to_owned(), "owner2".to_owned()],
-                    donation_denom: "eth".to_owned()
-                }, 
-                &[], 
-                "Contract", 
-                None
+                    donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
+                },
+                &[],
+                "Contract",
+                None,
             )
             .unwrap();
 
         app.execute_contract(
-            Addr::unchecked("owner1"), 
-            addr.clone(), 
-            &ExecuteMsg::AddMembers { admins: vec!["owner1".to_owned()] },
-            &[]
-        ).unwrap();
+            Addr::unchecked("owner1"),
+            addr.clone(),
+            &ExecuteMsg::AddMembers {
+                admins: vec!["owner1".to_owned()],
+            },
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &ExecuteMsg::Donate {},
+            &coins(4, "eth"),
+        )
+        .unwrap();
+
+        app.update_block(|block| block.time = block.time.plus_seconds(1001));
 
         app.execute_contract(
-            Addr::unchecked("user"), 
-            addr.clone(), 
-            &ExecuteMsg::Donate {}, 
-            &coins(4, "eth")
-        ).unwrap();
+            Addr::unchecked("user"),
+            addr.clone(),
+            &ExecuteMsg::Distribute {},
+            &[],
+        )
+        .unwrap();
 
         assert_eq!(
             app.wrap()
@@ -534,4 +1452,1128 @@ mod test {
             2
         );
     }
+
+    #[test]
+    fn bond_weighted_donate() {
+        let mut app = App::new(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("staker1"), coins(30, "eth"))
+                .unwrap();
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("staker2"), coins(10, "eth"))
+                .unwrap();
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("donor"), coins(9, "eth"))
+                .unwrap();
+        });
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked("owner"),
+                &InstantiateMsg {
+                    admins: vec![],
+                    donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        // staker1 bonds 30 tokens (weight 3), staker2 bonds 10 tokens (weight 1).
+        app.execute_contract(
+            Addr::unchecked("staker1"),
+            addr.clone(),
+            &ExecuteMsg::Bond {},
+            &coins(30, "eth"),
+        )
+        .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("staker2"),
+            addr.clone(),
+            &ExecuteMsg::Bond {},
+            &coins(10, "eth"),
+        )
+        .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("donor"),
+            addr.clone(),
+            &ExecuteMsg::Donate {},
+            &coins(8, "eth"),
+        )
+        .unwrap();
+
+        app.update_block(|block| block.time = block.time.plus_seconds(1001));
+
+        app.execute_contract(
+            Addr::unchecked("donor"),
+            addr.clone(),
+            &ExecuteMsg::Distribute {},
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(
+            app.wrap()
+                .query_balance(Addr::unchecked("staker1"), "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            6
+        );
+        assert_eq!(
+            app.wrap()
+                .query_balance(Addr::unchecked("staker2"), "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            2
+        );
+    }
+
+    #[test]
+    fn donate_rejected_outside_window() {
+        let mut app = App::new(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("donor"), coins(10, "eth"))
+                .unwrap()
+        });
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let start = mock_env().block.time.plus_seconds(100);
+        let deadline = mock_env().block.time.plus_seconds(200);
+
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked("owner"),
+                &InstantiateMsg {
+                    admins: vec!["owner".to_owned()],
+                    donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::zero(),
+                    start: Some(start),
+                    deadline,
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("donor"),
+                addr.clone(),
+                &ExecuteMsg::Donate {},
+                &coins(10, "eth"),
+            )
+            .unwrap_err();
+        assert_eq!(ContractError::RoundNotOpen {}, err.downcast().unwrap());
+
+        app.update_block(|block| block.time = block.time.plus_seconds(201));
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("donor"),
+                addr,
+                &ExecuteMsg::Donate {},
+                &coins(10, "eth"),
+            )
+            .unwrap_err();
+        assert_eq!(ContractError::RoundNotOpen {}, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn distribute_with_no_admins_or_stakers_errors_instead_of_panicking() {
+        let mut app = App::new(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("donor"), coins(10, "eth"))
+                .unwrap()
+        });
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked("owner"),
+                &InstantiateMsg {
+                    admins: vec![],
+                    donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("donor"),
+            addr.clone(),
+            &ExecuteMsg::Donate {},
+            &coins(10, "eth"),
+        )
+        .unwrap();
+
+        app.update_block(|block| block.time = block.time.plus_seconds(1001));
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("donor"),
+                addr,
+                &ExecuteMsg::Distribute {},
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoEligibleRecipients {},
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn refund_allowed_when_goal_met_but_no_eligible_recipients() {
+        let mut app = App::new(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("donor"), coins(10, "eth"))
+                .unwrap()
+        });
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked("owner"),
+                &InstantiateMsg {
+                    admins: vec![],
+                    donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("donor"),
+            addr.clone(),
+            &ExecuteMsg::Donate {},
+            &coins(10, "eth"),
+        )
+        .unwrap();
+
+        app.update_block(|block| block.time = block.time.plus_seconds(1001));
+
+        // Nobody is left to distribute to (no admins, nobody bonded), so `Distribute` still
+        // rejects even though the zero-token goal was met...
+        let err = app
+            .execute_contract(
+                Addr::unchecked("donor"),
+                addr.clone(),
+                &ExecuteMsg::Distribute {},
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoEligibleRecipients {},
+            err.downcast().unwrap()
+        );
+
+        // ...but the donor can still get their funds back instead of losing them forever.
+        app.execute_contract(Addr::unchecked("donor"), addr, &ExecuteMsg::Refund {}, &[])
+            .unwrap();
+
+        assert_eq!(
+            app.wrap()
+                .query_balance(Addr::unchecked("donor"), "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            10
+        );
+    }
+
+    #[test]
+    fn refund_when_goal_not_met() {
+        let mut app = App::new(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("donor1"), coins(3, "eth"))
+                .unwrap();
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("donor2"), coins(2, "eth"))
+                .unwrap();
+        });
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked("owner"),
+                &InstantiateMsg {
+                    admins: vec!["owner".to_owned()],
+                    donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::new(100),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("donor1"),
+            addr.clone(),
+            &ExecuteMsg::Donate {},
+            &coins(3, "eth"),
+        )
+        .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("donor2"),
+            addr.clone(),
+            &ExecuteMsg::Donate {},
+            &coins(2, "eth"),
+        )
+        .unwrap();
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("donor1"),
+                addr.clone(),
+                &ExecuteMsg::Distribute {},
+                &[],
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast().unwrap(),
+            ContractError::RoundNotFinished {}
+        ));
+
+        app.update_block(|block| block.time = block.time.plus_seconds(1001));
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("donor1"),
+                addr.clone(),
+                &ExecuteMsg::Distribute {},
+                &[],
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast().unwrap(),
+            ContractError::GoalNotMet { .. }
+        ));
+
+        app.execute_contract(
+            Addr::unchecked("donor1"),
+            addr.clone(),
+            &ExecuteMsg::Refund {},
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(
+            app.wrap()
+                .query_balance(Addr::unchecked("donor1"), "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            3
+        );
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("donor1"),
+                addr.clone(),
+                &ExecuteMsg::Refund {},
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(ContractError::NothingToRefund {}, err.downcast().unwrap());
+
+        app.execute_contract(Addr::unchecked("donor2"), addr, &ExecuteMsg::Refund {}, &[])
+            .unwrap();
+
+        assert_eq!(
+            app.wrap()
+                .query_balance(Addr::unchecked("donor2"), "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            2
+        );
+    }
+
+    #[test]
+    fn distribute_equal_split_skips_zero_shares() {
+        let mut app = App::new(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("donor"), coins(2, "eth"))
+                .unwrap()
+        });
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        // 3 admins splitting 2 tokens: each admin's equal share rounds down to zero.
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked("owner"),
+                &InstantiateMsg {
+                    admins: vec![
+                        "admin1".to_owned(),
+                        "admin2".to_owned(),
+                        "admin3".to_owned(),
+                    ],
+                    donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("donor"),
+            addr.clone(),
+            &ExecuteMsg::Donate {},
+            &coins(2, "eth"),
+        )
+        .unwrap();
+
+        app.update_block(|block| block.time = block.time.plus_seconds(1001));
+
+        app.execute_contract(
+            Addr::unchecked("donor"),
+            addr,
+            &ExecuteMsg::Distribute {},
+            &[],
+        )
+        .unwrap();
+
+        for admin in ["admin1", "admin2", "admin3"] {
+            assert_eq!(
+                app.wrap()
+                    .query_balance(Addr::unchecked(admin), "eth")
+                    .unwrap()
+                    .amount
+                    .u128(),
+                0
+            );
+        }
+    }
+
+    #[test]
+    fn claim_before_unbonding_period_is_rejected() {
+        let mut app = App::new(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("staker"), coins(10, "eth"))
+                .unwrap()
+        });
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked("owner"),
+                &InstantiateMsg {
+                    admins: vec![],
+                    donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(100),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("staker"),
+            addr.clone(),
+            &ExecuteMsg::Bond {},
+            &coins(10, "eth"),
+        )
+        .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("staker"),
+            addr.clone(),
+            &ExecuteMsg::Unbond {
+                amount: Uint128::new(1),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let err = app
+            .execute_contract(Addr::unchecked("staker"), addr, &ExecuteMsg::Claim {}, &[])
+            .unwrap_err();
+
+        assert_eq!(ContractError::NothingToClaim {}, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn claim_after_unbonding_period() {
+        let mut app = App::new(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("staker"), coins(20, "eth"))
+                .unwrap()
+        });
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked("owner"),
+                &InstantiateMsg {
+                    admins: vec![],
+                    donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(100),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("staker"),
+            addr.clone(),
+            &ExecuteMsg::Bond {},
+            &coins(20, "eth"),
+        )
+        .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("staker"),
+            addr.clone(),
+            &ExecuteMsg::Unbond {
+                amount: Uint128::new(20),
+            },
+            &[],
+        )
+        .unwrap();
+
+        app.update_block(|block| block.time = block.time.plus_seconds(101));
+
+        app.execute_contract(
+            Addr::unchecked("staker"),
+            addr.clone(),
+            &ExecuteMsg::Claim {},
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(
+            app.wrap()
+                .query_balance(Addr::unchecked("staker"), "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            20
+        );
+    }
+
+    #[test]
+    fn add_and_remove_hook() {
+        let mut app = App::default();
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked("owner"),
+                &InstantiateMsg {
+                    admins: vec!["owner".to_owned()],
+                    donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("user"),
+                addr.clone(),
+                &ExecuteMsg::AddHook {
+                    addr: "subscriber".to_owned(),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: Addr::unchecked("user")
+            },
+            err.downcast().unwrap()
+        );
+
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            addr.clone(),
+            &ExecuteMsg::AddHook {
+                addr: "subscriber".to_owned(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let resp: HooksResp = app
+            .wrap()
+            .query_wasm_smart(addr.clone(), &QueryMsg::Hooks {})
+            .unwrap();
+        assert_eq!(
+            resp,
+            HooksResp {
+                hooks: vec![Addr::unchecked("subscriber")]
+            }
+        );
+
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            addr.clone(),
+            &ExecuteMsg::RemoveHook {
+                addr: "subscriber".to_owned(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let resp: HooksResp = app
+            .wrap()
+            .query_wasm_smart(addr, &QueryMsg::Hooks {})
+            .unwrap();
+        assert_eq!(resp, HooksResp { hooks: vec![] });
+    }
+
+    #[test]
+    fn spend_within_allowance() {
+        let mut app = App::new(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("owner"), coins(10, "eth"))
+                .unwrap()
+        });
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked("owner"),
+                &InstantiateMsg {
+                    admins: vec!["owner".to_owned()],
+                    donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+        app.send_tokens(Addr::unchecked("owner"), addr.clone(), &coins(10, "eth"))
+            .unwrap();
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("helper"),
+                addr.clone(),
+                &ExecuteMsg::Spend {
+                    to: "recipient".to_owned(),
+                    amount: Uint128::new(3),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoAllowance {
+                spender: Addr::unchecked("helper")
+            },
+            err.downcast().unwrap()
+        );
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("user"),
+                addr.clone(),
+                &ExecuteMsg::IncreaseAllowance {
+                    spender: "helper".to_owned(),
+                    amount: Uint128::new(5),
+                    expires: None,
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: Addr::unchecked("user")
+            },
+            err.downcast().unwrap()
+        );
+
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            addr.clone(),
+            &ExecuteMsg::IncreaseAllowance {
+                spender: "helper".to_owned(),
+                amount: Uint128::new(5),
+                expires: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let resp: AllowanceResp = app
+            .wrap()
+            .query_wasm_smart(
+                addr.clone(),
+                &QueryMsg::Allowance {
+                    spender: "helper".to_owned(),
+                },
+            )
+            .unwrap();
+        assert_eq!(resp.balance, coin(5, "eth"));
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("helper"),
+                addr.clone(),
+                &ExecuteMsg::Spend {
+                    to: "recipient".to_owned(),
+                    amount: Uint128::new(6),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InsufficientAllowance {
+                requested: Uint128::new(6),
+                available: Uint128::new(5),
+            },
+            err.downcast().unwrap()
+        );
+
+        app.execute_contract(
+            Addr::unchecked("helper"),
+            addr.clone(),
+            &ExecuteMsg::Spend {
+                to: "recipient".to_owned(),
+                amount: Uint128::new(3),
+            },
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(
+            app.wrap()
+                .query_balance(Addr::unchecked("recipient"), "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            3
+        );
+
+        let resp: AllowanceResp = app
+            .wrap()
+            .query_wasm_smart(
+                addr,
+                &QueryMsg::Allowance {
+                    spender: "helper".to_owned(),
+                },
+            )
+            .unwrap();
+        assert_eq!(resp.balance, coin(2, "eth"));
+    }
+
+    #[test]
+    fn spend_cannot_draw_on_staked_or_donated_funds() {
+        let mut app = App::new(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("staker"), coins(10, "eth"))
+                .unwrap();
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("donor"), coins(5, "eth"))
+                .unwrap();
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("owner"), coins(5, "eth"))
+                .unwrap();
+        });
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked("owner"),
+                &InstantiateMsg {
+                    admins: vec!["owner".to_owned()],
+                    donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        // 10 tokens bonded and 5 donated are owed back to the staker and donor; only the 5
+        // tokens sent straight to the contract's treasury are actually spendable.
+        app.execute_contract(
+            Addr::unchecked("staker"),
+            addr.clone(),
+            &ExecuteMsg::Bond {},
+            &coins(10, "eth"),
+        )
+        .unwrap();
+        app.execute_contract(
+            Addr::unchecked("donor"),
+            addr.clone(),
+            &ExecuteMsg::Donate {},
+            &coins(5, "eth"),
+        )
+        .unwrap();
+        app.send_tokens(Addr::unchecked("owner"), addr.clone(), &coins(5, "eth"))
+            .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            addr.clone(),
+            &ExecuteMsg::IncreaseAllowance {
+                spender: "helper".to_owned(),
+                amount: Uint128::new(6),
+                expires: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("helper"),
+                addr.clone(),
+                &ExecuteMsg::Spend {
+                    to: "recipient".to_owned(),
+                    amount: Uint128::new(6),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InsufficientTreasuryBalance {
+                requested: Uint128::new(6),
+                available: Uint128::new(5),
+            },
+            err.downcast().unwrap()
+        );
+
+        app.execute_contract(
+            Addr::unchecked("helper"),
+            addr.clone(),
+            &ExecuteMsg::Spend {
+                to: "recipient".to_owned(),
+                amount: Uint128::new(5),
+            },
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(
+            app.wrap()
+                .query_balance(Addr::unchecked("recipient"), "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            5
+        );
+    }
+
+    #[test]
+    fn spend_rejects_expired_allowance() {
+        let mut app = App::default();
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked("owner"),
+                &InstantiateMsg {
+                    admins: vec!["owner".to_owned()],
+                    donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            addr.clone(),
+            &ExecuteMsg::IncreaseAllowance {
+                spender: "helper".to_owned(),
+                amount: Uint128::new(5),
+                expires: Some(cw_utils::Expiration::AtHeight(app.block_info().height + 1)),
+            },
+            &[],
+        )
+        .unwrap();
+
+        app.update_block(|block| block.height += 2);
+
+        // The allowance has expired, so `Spend` treats it as zero and is rejected; the rejected
+        // call's storage writes are rolled back, so the stale entry stays in storage...
+        let err = app
+            .execute_contract(
+                Addr::unchecked("helper"),
+                addr.clone(),
+                &ExecuteMsg::Spend {
+                    to: "recipient".to_owned(),
+                    amount: Uint128::new(1),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoAllowance {
+                spender: Addr::unchecked("helper")
+            },
+            err.downcast().unwrap()
+        );
+
+        // ...but a later grant still treats it as zero and overwrites it, so no leftover balance
+        // is built on top of.
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            addr.clone(),
+            &ExecuteMsg::IncreaseAllowance {
+                spender: "helper".to_owned(),
+                amount: Uint128::new(2),
+                expires: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let resp: AllowanceResp = app
+            .wrap()
+            .query_wasm_smart(
+                addr,
+                &QueryMsg::Allowance {
+                    spender: "helper".to_owned(),
+                },
+            )
+            .unwrap();
+        assert_eq!(resp.balance, coin(2, "eth"));
+    }
+
+    #[test]
+    fn decrease_allowance_to_zero_removes_it() {
+        let mut app = App::default();
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked("owner"),
+                &InstantiateMsg {
+                    admins: vec!["owner".to_owned()],
+                    donation_denom: "eth".to_owned(),
+                    tokens_per_weight: Uint128::new(10),
+                    min_bond: Uint128::new(10),
+                    unbonding_period: Duration::Time(1),
+                    goal: Uint128::zero(),
+                    start: None,
+                    deadline: mock_env().block.time.plus_seconds(1000),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            addr.clone(),
+            &ExecuteMsg::IncreaseAllowance {
+                spender: "helper".to_owned(),
+                amount: Uint128::new(5),
+                expires: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            addr.clone(),
+            &ExecuteMsg::DecreaseAllowance {
+                spender: "helper".to_owned(),
+                amount: Uint128::new(10),
+                expires: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let resp: AllowanceResp = app
+            .wrap()
+            .query_wasm_smart(
+                addr,
+                &QueryMsg::Allowance {
+                    spender: "helper".to_owned(),
+                },
+            )
+            .unwrap();
+        assert_eq!(resp.balance, coin(0, "eth"));
+        assert_eq!(resp.expires, cw_utils::Expiration::Never {});
+    }
+
+    #[test]
+    fn migrate_same_version_succeeds() {
+        let mut deps = mock_dependencies();
+
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner", &[]),
+            InstantiateMsg {
+                admins: vec![],
+                donation_denom: "eth".to_owned(),
+                tokens_per_weight: Uint128::new(10),
+                min_bond: Uint128::new(10),
+                unbonding_period: Duration::Time(1),
+                goal: Uint128::zero(),
+                start: None,
+                deadline: mock_env().block.time.plus_seconds(1000),
+            },
+        )
+        .unwrap();
+
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    }
+
+    #[test]
+    fn migrate_rejects_mismatched_contract() {
+        let mut deps = mock_dependencies();
+
+        CONTRACT_INFO
+            .save(
+                deps.as_mut().storage,
+                &ContractInfo {
+                    contract: "crates.io:some-other-contract".to_owned(),
+                    version: CONTRACT_VERSION.to_owned(),
+                },
+            )
+            .unwrap();
+
+        let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+
+        assert!(matches!(err, ContractError::CannotMigrate { .. }));
+    }
 }
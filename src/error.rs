@@ -0,0 +1,71 @@
+use cosmwasm_std::{Addr, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Payment(#[from] cw_utils::PaymentError),
+
+    #[error("{sender} is not contract admin")]
+    Unauthorized { sender: Addr },
+
+    #[error("cannot unbond {requested} tokens, only {available} are staked")]
+    InsufficientStake {
+        requested: cosmwasm_std::Uint128,
+        available: cosmwasm_std::Uint128,
+    },
+
+    #[error("no matured claims are available to release")]
+    NothingToClaim {},
+
+    #[error("cannot migrate: {reason}")]
+    CannotMigrate { reason: String },
+
+    #[error("the donation round is not currently open")]
+    RoundNotOpen {},
+
+    #[error("the donation round has not reached its deadline yet")]
+    RoundNotFinished {},
+
+    #[error("cannot distribute: only {raised} of {goal} raised")]
+    GoalNotMet {
+        raised: cosmwasm_std::Uint128,
+        goal: cosmwasm_std::Uint128,
+    },
+
+    #[error("cannot refund: funding goal of {goal} was met with {raised} raised")]
+    GoalMet {
+        raised: cosmwasm_std::Uint128,
+        goal: cosmwasm_std::Uint128,
+    },
+
+    #[error("nothing to refund")]
+    NothingToRefund {},
+
+    #[error("cannot distribute: no admins or stakers to receive the payout")]
+    NoEligibleRecipients {},
+
+    #[error("{spender} has no allowance")]
+    NoAllowance { spender: Addr },
+
+    #[error("tokens_per_weight must be greater than zero")]
+    ZeroTokensPerWeight {},
+
+    #[error("min_bond must be greater than zero")]
+    ZeroMinBond {},
+
+    #[error("spender can only spend {available}, requested {requested}")]
+    InsufficientAllowance {
+        requested: cosmwasm_std::Uint128,
+        available: cosmwasm_std::Uint128,
+    },
+
+    #[error("only {available} of the contract's balance is spendable (the rest is reserved for staker claims and donation refunds), requested {requested}")]
+    InsufficientTreasuryBalance {
+        requested: cosmwasm_std::Uint128,
+        available: cosmwasm_std::Uint128,
+    },
+}
@@ -2,7 +2,7 @@ use cosmwasm_std::{
     entry_point, to_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
 };
 use error::ContractError;
-use msg::InstantiateMsg;
+use msg::{InstantiateMsg, MigrateMsg};
 
 pub mod contract;
 pub mod error;
@@ -15,7 +15,7 @@ pub fn instantiate(
     env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
     contract::instantiate(deps, env, info, msg)
 }
 
@@ -33,3 +33,8 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     contract::execute(deps, env, info, msg)
 }
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    contract::migrate(deps, env, msg)
+}
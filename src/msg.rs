@@ -1,5 +1,6 @@
-use cosmwasm_std::Addr;
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Coin, Timestamp, Uint128};
+use cw_utils::{Duration, Expiration};
 
 #[cw_serde]
 #[derive(QueryResponses)]
@@ -7,7 +8,26 @@ pub enum QueryMsg {
     #[returns(GreetResp)]
     Greet {},
     #[returns(AdminListResp)]
-    AdminsList {},
+    AdminsList {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(HooksResp)]
+    Hooks {},
+    #[returns(FundersResp)]
+    Funders {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(TotalRaisedResp)]
+    TotalRaised {},
+    #[returns(AllowanceResp)]
+    Allowance { spender: String },
+    #[returns(AllAllowancesResp)]
+    AllAllowances {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -19,16 +39,123 @@ pub struct GreetResp {
 pub struct InstantiateMsg {
     pub admins: Vec<String>,
     pub donation_denom: String,
+    /// Amount of staked tokens which equals one unit of voting weight.
+    pub tokens_per_weight: Uint128,
+    /// Stakers with less than this bonded are treated as having zero weight.
+    pub min_bond: Uint128,
+    /// How long a staker has to wait after unbonding before a claim matures.
+    pub unbonding_period: Duration,
+    /// Amount the donation round must raise before `Distribute` will pay out.
+    pub goal: Uint128,
+    /// Donations are rejected before this time, if set.
+    pub start: Option<Timestamp>,
+    /// Donations are rejected at or after this time; the round settles via `Distribute` or `Refund`.
+    pub deadline: Timestamp,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    AddMembers { admins: Vec<String> },
+    AddMembers {
+        admins: Vec<String>,
+    },
     Leave {},
     Donate {},
+    Bond {},
+    Unbond {
+        amount: Uint128,
+    },
+    Claim {},
+    AddHook {
+        addr: String,
+    },
+    RemoveHook {
+        addr: String,
+    },
+    Distribute {},
+    Refund {},
+    IncreaseAllowance {
+        spender: String,
+        amount: Uint128,
+        expires: Option<Expiration>,
+    },
+    DecreaseAllowance {
+        spender: String,
+        amount: Uint128,
+        expires: Option<Expiration>,
+    },
+    Spend {
+        to: String,
+        amount: Uint128,
+    },
 }
 
+#[cw_serde]
+pub struct MigrateMsg {}
+
 #[cw_serde]
 pub struct AdminListResp {
     pub admins: Vec<Addr>,
 }
+
+#[cw_serde]
+pub struct HooksResp {
+    pub hooks: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct FunderInfo {
+    pub addr: Addr,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct FundersResp {
+    pub funders: Vec<FunderInfo>,
+}
+
+#[cw_serde]
+pub struct TotalRaisedResp {
+    pub total: Uint128,
+}
+
+#[cw_serde]
+pub struct AllowanceResp {
+    pub balance: Coin,
+    pub expires: Expiration,
+}
+
+#[cw_serde]
+pub struct AllowanceInfo {
+    pub spender: Addr,
+    pub balance: Coin,
+    pub expires: Expiration,
+}
+
+#[cw_serde]
+pub struct AllAllowancesResp {
+    pub allowances: Vec<AllowanceInfo>,
+}
+
+/// Sent to every subscriber registered via `AddHook` whenever the admin list changes.
+#[cw_serde]
+pub struct MemberChangedHookMsg {
+    pub diffs: Vec<MemberDiff>,
+}
+
+/// A single admin's weight transition; `None` means "not a member" on that side of the change.
+#[cw_serde]
+pub struct MemberDiff {
+    pub addr: Addr,
+    pub old_weight: Option<u64>,
+    pub new_weight: Option<u64>,
+}
+
+impl MemberDiff {
+    pub fn new(addr: Addr, old_weight: Option<u64>, new_weight: Option<u64>) -> Self {
+        Self {
+            addr,
+            old_weight,
+            new_weight,
+        }
+    }
+}
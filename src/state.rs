@@ -0,0 +1,58 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Empty, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+use cw_utils::Expiration;
+
+pub const ADMINS: Map<&Addr, Empty> = Map::new("admins");
+pub const DONATION_DENOM: Item<String> = Item::new("donation_denom");
+
+/// Contracts subscribed to membership-changed notifications, see [`crate::msg::MemberChangedHookMsg`].
+pub const HOOKS: Map<&Addr, Empty> = Map::new("hooks");
+
+pub const TOKENS_PER_WEIGHT: Item<Uint128> = Item::new("tokens_per_weight");
+pub const MIN_BOND: Item<Uint128> = Item::new("min_bond");
+pub const UNBONDING_PERIOD: Item<cw_utils::Duration> = Item::new("unbonding_period");
+
+pub const STAKE: Map<&Addr, Uint128> = Map::new("stake");
+pub const TOTAL: Item<Uint128> = Item::new("total_weight");
+
+/// Total tokens currently owed back to stakers, whether still bonded or awaiting a matured
+/// claim; reserved out of the contract's balance so `Spend` can never touch them.
+pub const TOTAL_BONDED: Item<Uint128> = Item::new("total_bonded");
+
+#[cw_serde]
+pub struct Claim {
+    pub amount: Uint128,
+    pub release_at: Expiration,
+}
+
+pub const CLAIMS: Map<&Addr, Vec<Claim>> = Map::new("claims");
+
+/// Stored name/version of the contract last run against this storage, cw2-style.
+#[cw_serde]
+pub struct ContractInfo {
+    pub contract: String,
+    pub version: String,
+}
+
+pub const CONTRACT_INFO: Item<ContractInfo> = Item::new("contract_info");
+
+/// Minimum amount that must be raised before `Distribute` is allowed to pay out.
+pub const GOAL: Item<Uint128> = Item::new("goal");
+/// Donations are rejected before this time, if set.
+pub const START: Item<Option<Timestamp>> = Item::new("start");
+/// Donations are rejected at or after this time; `Distribute`/`Refund` only run once it is reached.
+pub const DEADLINE: Item<Timestamp> = Item::new("deadline");
+
+pub const FUNDERS: Map<&Addr, Uint128> = Map::new("funders");
+pub const TOTAL_RAISED: Item<Uint128> = Item::new("total_raised");
+
+/// A spending limit granted to a non-admin address against the contract's own balance,
+/// cw1-subkeys-style.
+#[cw_serde]
+pub struct Allowance {
+    pub balance: Coin,
+    pub expires: Expiration,
+}
+
+pub const ALLOWANCES: Map<&Addr, Allowance> = Map::new("allowances");